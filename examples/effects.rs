@@ -0,0 +1,19 @@
+use cute_lights::{
+    discover_lights,
+    effects::{ColorWheel, Player},
+    CuteResult,
+};
+
+#[tokio::main]
+async fn main() -> CuteResult<()> {
+    let lights = discover_lights().await;
+    println!("Found {} lights", lights.len());
+
+    let player = Player::new(lights, 30);
+    player
+        .play(Box::new(ColorWheel {
+            speed: 0.1,
+            brightness: 255,
+        }))
+        .await
+}
@@ -0,0 +1,6 @@
+use cute_lights::{wizard, CuteResult};
+
+#[tokio::main]
+async fn main() -> CuteResult<()> {
+    wizard::run().await
+}
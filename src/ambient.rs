@@ -0,0 +1,208 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use xcap::Monitor;
+
+use crate::{utils::future::FutureBatch, Light};
+
+// ANCHOR - AmbientConfig
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct AmbientConfig {
+    pub enabled: bool,
+    #[serde(default = "default_fps")]
+    pub fps: u32,
+    /// Number of screen regions to sample, left-to-right. Lights are mapped
+    /// to regions in discovery order; a single region means every light
+    /// tracks the whole-screen average.
+    #[serde(default = "default_regions")]
+    pub regions: usize,
+    /// Exponential smoothing factor applied to each channel, `0.0..=1.0`.
+    #[serde(default = "default_smoothing")]
+    pub smoothing: f32,
+}
+
+impl Default for AmbientConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            fps: default_fps(),
+            regions: default_regions(),
+            smoothing: default_smoothing(),
+        }
+    }
+}
+
+fn default_fps() -> u32 {
+    15
+}
+
+fn default_regions() -> usize {
+    1
+}
+
+fn default_smoothing() -> f32 {
+    0.2
+}
+
+// ANCHOR - AmbientController
+
+pub struct AmbientController {
+    lights: Vec<Box<dyn Light>>,
+    config: AmbientConfig,
+    smoothed: Vec<(f32, f32, f32)>,
+}
+
+impl AmbientController {
+    pub fn new(lights: Vec<Box<dyn Light>>, config: AmbientConfig) -> Self {
+        let smoothed = vec![(0.0, 0.0, 0.0); lights.len().max(1)];
+        Self {
+            lights,
+            config,
+            smoothed,
+        }
+    }
+
+    pub async fn run(mut self) -> anyhow::Result<()> {
+        let mut interval = tokio::time::interval(Duration::from_secs_f64(1.0 / self.config.fps.max(1) as f64));
+
+        loop {
+            interval.tick().await;
+
+            let monitor = match Monitor::all().map(|monitors| {
+                monitors.into_iter().find(|m| m.is_primary())
+            }) {
+                Ok(Some(monitor)) => monitor,
+                Ok(None) => {
+                    eprintln!("Ambient controller: no primary display found");
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("Ambient controller: failed to enumerate displays: {}", e);
+                    continue;
+                }
+            };
+            let frame = match monitor.capture_image() {
+                Ok(frame) => frame,
+                Err(e) => {
+                    eprintln!("Ambient controller: failed to capture screen: {}", e);
+                    continue;
+                }
+            };
+
+            let region_count = self.config.regions.max(1).min(self.lights.len().max(1));
+            let regions = average_regions(&frame, region_count);
+
+            let mut batch = FutureBatch::new();
+            for (index, light) in self.lights.drain(..).enumerate() {
+                let (r, g, b) = regions[index % regions.len()];
+                let smoothed_len = self.smoothed.len();
+                let prev = self.smoothed[index % smoothed_len];
+                let alpha = self.config.smoothing;
+                let smoothed = (
+                    prev.0 * (1.0 - alpha) + r as f32 * alpha,
+                    prev.1 * (1.0 - alpha) + g as f32 * alpha,
+                    prev.2 * (1.0 - alpha) + b as f32 * alpha,
+                );
+                self.smoothed[index % smoothed_len] = smoothed;
+
+                let mut light = light;
+                batch.push(async move {
+                    let _ = light
+                        .set_color(smoothed.0 as u8, smoothed.1 as u8, smoothed.2 as u8)
+                        .await;
+                    light
+                });
+            }
+            self.lights = batch.run().await;
+        }
+    }
+}
+
+/// Splits the captured frame into `region_count` vertical slices and returns
+/// the per-region average RGB, computed by summing channels over each
+/// region's pixels and dividing by pixel count.
+fn average_regions(frame: &xcap::image::RgbaImage, region_count: usize) -> Vec<(u8, u8, u8)> {
+    let (width, height) = frame.dimensions();
+    // Never slice more regions than there are columns: with more regions than
+    // pixels, `region_width` would floor to 1 while some regions still start
+    // at or past `width`, which panics in `get_pixel` below.
+    let region_count = region_count.min(width.max(1) as usize).max(1);
+    let region_width = (width / region_count as u32).max(1);
+
+    (0..region_count)
+        .map(|region| {
+            let start_x = region as u32 * region_width;
+            let end_x = if region == region_count - 1 {
+                width
+            } else {
+                start_x + region_width
+            };
+
+            let (mut r_sum, mut g_sum, mut b_sum, mut count) = (0u64, 0u64, 0u64, 0u64);
+            for y in 0..height {
+                for x in start_x..end_x {
+                    let pixel = frame.get_pixel(x, y);
+                    r_sum += pixel[0] as u64;
+                    g_sum += pixel[1] as u64;
+                    b_sum += pixel[2] as u64;
+                    count += 1;
+                }
+            }
+
+            let count = count.max(1);
+            ((r_sum / count) as u8, (g_sum / count) as u8, (b_sum / count) as u8)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use xcap::image::RgbaImage;
+
+    fn solid_frame(width: u32, height: u32, rgb: (u8, u8, u8)) -> RgbaImage {
+        RgbaImage::from_fn(width, height, |_, _| {
+            xcap::image::Rgba([rgb.0, rgb.1, rgb.2, 255])
+        })
+    }
+
+    #[test]
+    fn average_regions_single_region_is_whole_frame_average() {
+        let frame = solid_frame(4, 4, (10, 20, 30));
+        assert_eq!(average_regions(&frame, 1), vec![(10, 20, 30)]);
+    }
+
+    #[test]
+    fn average_regions_splits_left_to_right() {
+        let mut frame = solid_frame(4, 2, (0, 0, 0));
+        for y in 0..2 {
+            for x in 2..4 {
+                frame.put_pixel(x, y, xcap::image::Rgba([255, 255, 255, 255]));
+            }
+        }
+
+        let regions = average_regions(&frame, 2);
+        assert_eq!(regions, vec![(0, 0, 0), (255, 255, 255)]);
+    }
+
+    #[test]
+    fn average_regions_last_region_absorbs_remainder() {
+        // width=5, region_count=2 => region_width=2, so the last region must
+        // cover the trailing odd column instead of dropping it.
+        let frame = solid_frame(5, 1, (42, 42, 42));
+        let regions = average_regions(&frame, 2);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions[1], (42, 42, 42));
+    }
+
+    #[test]
+    fn average_regions_clamps_region_count_to_frame_width() {
+        // More requested regions than pixel columns must not panic in
+        // `get_pixel`; the result should fall back to one region per column.
+        let frame = solid_frame(2, 1, (7, 7, 7));
+        let regions = average_regions(&frame, 8);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(regions, vec![(7, 7, 7), (7, 7, 7)]);
+    }
+}
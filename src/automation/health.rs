@@ -0,0 +1,163 @@
+use std::{sync::Arc, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, Barrier};
+
+use super::{Event, Level, Monitor};
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProbeKind {
+    Dns,
+    Tcp,
+    Http,
+}
+
+/// Routing is not configured here: pair each check with an
+/// `automation.rules[]` entry whose `source` is `health::<target>` to pick
+/// which lights react to it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HealthCheckConfig {
+    pub target: String,
+    pub kind: ProbeKind,
+    pub resolver: Option<String>,
+    pub period_secs: u64,
+}
+
+// ANCHOR - HealthMonitor
+
+pub struct HealthMonitor {
+    checks: Vec<HealthCheckConfig>,
+}
+
+impl HealthMonitor {
+    pub fn new(checks: Vec<HealthCheckConfig>) -> Self {
+        Self { checks }
+    }
+}
+
+#[async_trait::async_trait]
+impl Monitor for HealthMonitor {
+    fn name(&self) -> String {
+        "health".to_string()
+    }
+
+    async fn run(self: Box<Self>, events: mpsc::Sender<Event>, barrier: Arc<Barrier>) {
+        barrier.wait().await;
+
+        let tasks: Vec<_> = self
+            .checks
+            .into_iter()
+            .map(|check| {
+                let events = events.clone();
+                tokio::spawn(run_check(check, events))
+            })
+            .collect();
+
+        for task in tasks {
+            let _ = task.await;
+        }
+    }
+}
+
+/// Runs a single check on its own interval timer for the lifetime of the
+/// monitor, emitting an event only when the derived status actually changes.
+async fn run_check(check: HealthCheckConfig, events: mpsc::Sender<Event>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(check.period_secs.max(1)));
+    let mut last_level = None;
+
+    loop {
+        interval.tick().await;
+        let level = probe(&check).await;
+
+        if should_emit(last_level, level) {
+            last_level = Some(level);
+            let event = Event {
+                source: format!("health::{}", check.target),
+                level,
+                payload: serde_json::json!({ "target": check.target }),
+            };
+            if events.send(event).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Debounces [`run_check`]'s per-tick probe result: only the first tick at a
+/// given level should emit an event, so repeated identical readings don't
+/// spam the dispatcher every period.
+fn should_emit(last_level: Option<Level>, level: Level) -> bool {
+    last_level != Some(level)
+}
+
+async fn probe(check: &HealthCheckConfig) -> Level {
+    match tokio::time::timeout(PROBE_TIMEOUT, run_probe(check)).await {
+        Ok(Ok(true)) => Level::Up,
+        Ok(Ok(false)) => Level::Degraded,
+        Ok(Err(_)) | Err(_) => Level::Down,
+    }
+}
+
+async fn run_probe(check: &HealthCheckConfig) -> anyhow::Result<bool> {
+    match check.kind {
+        ProbeKind::Dns => probe_dns(check).await,
+        ProbeKind::Tcp => probe_tcp(check).await,
+        ProbeKind::Http => probe_http(check).await,
+    }
+}
+
+async fn probe_dns(check: &HealthCheckConfig) -> anyhow::Result<bool> {
+    use hickory_resolver::{config::*, TokioAsyncResolver};
+
+    let resolver = match &check.resolver {
+        Some(addr) => {
+            let ip: std::net::IpAddr = addr.parse()?;
+            TokioAsyncResolver::tokio(
+                ResolverConfig::from_parts(
+                    None,
+                    vec![],
+                    NameServerConfigGroup::from_ips_clear(&[ip], 53, true),
+                ),
+                ResolverOpts::default(),
+            )
+        }
+        None => TokioAsyncResolver::tokio(ResolverConfig::default(), ResolverOpts::default()),
+    };
+
+    let response = resolver.lookup_ip(check.target.as_str()).await?;
+    Ok(response.iter().next().is_some())
+}
+
+async fn probe_tcp(check: &HealthCheckConfig) -> anyhow::Result<bool> {
+    tokio::net::TcpStream::connect(&check.target).await?;
+    Ok(true)
+}
+
+async fn probe_http(check: &HealthCheckConfig) -> anyhow::Result<bool> {
+    let response = reqwest::get(&check.target).await?;
+    Ok(response.status().is_success())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_emit_on_first_reading() {
+        assert!(should_emit(None, Level::Up));
+    }
+
+    #[test]
+    fn should_emit_on_level_change() {
+        assert!(should_emit(Some(Level::Up), Level::Down));
+    }
+
+    #[test]
+    fn should_not_emit_when_level_is_unchanged() {
+        assert!(!should_emit(Some(Level::Up), Level::Up));
+        assert!(!should_emit(Some(Level::Down), Level::Down));
+    }
+}
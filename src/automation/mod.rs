@@ -0,0 +1,328 @@
+pub mod health;
+
+use std::{collections::HashMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, Barrier, RwLock};
+
+use crate::Light;
+
+// ANCHOR - Event
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Level {
+    Up,
+    Degraded,
+    Down,
+}
+
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub source: String,
+    pub level: Level,
+    pub payload: serde_json::Value,
+}
+
+// ANCHOR - Monitor
+
+#[async_trait::async_trait]
+pub trait Monitor: Send {
+    fn name(&self) -> String;
+
+    /// Runs until the process exits, pushing events onto `events`. Must wait
+    /// on `barrier` before doing any real work so that monitors and the
+    /// dispatcher all come up together.
+    async fn run(self: Box<Self>, events: mpsc::Sender<Event>, barrier: Arc<Barrier>);
+}
+
+// ANCHOR - Rule
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Rule {
+    pub source: String,
+    pub level: Level,
+    pub light_ids: Vec<String>,
+    pub color: Option<(u8, u8, u8)>,
+    pub brightness: Option<u8>,
+}
+
+impl Rule {
+    fn matches(&self, event: &Event) -> bool {
+        self.source == event.source && self.level == event.level
+    }
+}
+
+// ANCHOR - AutomationConfig
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct AutomationConfig {
+    pub enabled: bool,
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+    #[serde(default)]
+    pub health_checks: Vec<health::HealthCheckConfig>,
+}
+
+// ANCHOR - Output
+
+/// A rule target: something a [`Rule`] can address by id and push color or
+/// brightness changes to. Discovered lights are the only implementation
+/// today, but the dispatcher only ever sees this trait, so other kinds of
+/// targets can be added without touching [`Dispatcher`].
+#[async_trait::async_trait]
+pub trait Output: Send {
+    fn id(&self) -> String;
+    async fn set_color(&mut self, red: u8, green: u8, blue: u8) -> anyhow::Result<()>;
+    async fn set_brightness(&mut self, brightness: u8) -> anyhow::Result<()>;
+}
+
+#[async_trait::async_trait]
+impl Output for Box<dyn Light> {
+    fn id(&self) -> String {
+        (**self).id()
+    }
+
+    async fn set_color(&mut self, red: u8, green: u8, blue: u8) -> anyhow::Result<()> {
+        (**self).set_color(red, green, blue).await
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> anyhow::Result<()> {
+        (**self).set_brightness(brightness).await
+    }
+}
+
+// ANCHOR - Dispatcher
+
+pub struct Dispatcher {
+    outputs: HashMap<String, Box<dyn Output>>,
+    rules: Arc<RwLock<Vec<Rule>>>,
+}
+
+impl Dispatcher {
+    fn new(outputs: Vec<Box<dyn Output>>, rules: Arc<RwLock<Vec<Rule>>>) -> Self {
+        Self {
+            outputs: outputs.into_iter().map(|o| (o.id(), o)).collect(),
+            rules,
+        }
+    }
+
+    async fn run(mut self, mut events: mpsc::Receiver<Event>, barrier: Arc<Barrier>) {
+        barrier.wait().await;
+
+        while let Some(event) = events.recv().await {
+            let rules = self.rules.read().await.clone();
+            for rule in rules.iter().filter(|rule| rule.matches(&event)) {
+                for output_id in &rule.light_ids {
+                    let Some(output) = self.outputs.get_mut(output_id) else {
+                        continue;
+                    };
+                    if let Some((r, g, b)) = rule.color {
+                        let _ = output.set_color(r, g, b).await;
+                    }
+                    if let Some(brightness) = rule.brightness {
+                        let _ = output.set_brightness(brightness).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// ANCHOR - RulesHandle
+
+/// A cloneable handle onto the live rule set of a running [`Automation`].
+///
+/// `Automation::run` consumes `self` and blocks for the life of the process,
+/// so this is the only way to reach the rules afterwards: grab a handle with
+/// [`Automation::handle`] before calling `run`, move it into whatever task
+/// needs to reload rules later, and the dispatcher will pick up the change on
+/// the next event.
+#[derive(Clone)]
+pub struct RulesHandle(Arc<RwLock<Vec<Rule>>>);
+
+impl RulesHandle {
+    /// Hot-swaps the active rule set without restarting any monitor.
+    pub async fn set_rules(&self, rules: Vec<Rule>) {
+        *self.0.write().await = rules;
+    }
+}
+
+// ANCHOR - Automation
+
+pub struct Automation {
+    monitors: Vec<Box<dyn Monitor>>,
+    rules: Arc<RwLock<Vec<Rule>>>,
+}
+
+impl Automation {
+    pub fn new(rules: Vec<Rule>) -> Self {
+        Self {
+            monitors: Vec::new(),
+            rules: Arc::new(RwLock::new(rules)),
+        }
+    }
+
+    pub fn add_monitor(&mut self, monitor: impl Monitor + 'static) {
+        self.monitors.push(Box::new(monitor));
+    }
+
+    /// Returns a cloneable handle that can reload the rule set after `run`
+    /// has taken ownership of `self` and started spinning.
+    pub fn handle(&self) -> RulesHandle {
+        RulesHandle(self.rules.clone())
+    }
+
+    pub async fn run(self, lights: Vec<Box<dyn Light>>) -> anyhow::Result<()> {
+        let (tx, rx) = mpsc::channel(128);
+        // One participant per monitor, plus the dispatcher itself, so nothing
+        // emits or consumes events until every task has started up.
+        let barrier = Arc::new(Barrier::new(self.monitors.len() + 1));
+
+        for monitor in self.monitors {
+            let tx = tx.clone();
+            let barrier = barrier.clone();
+            tokio::spawn(async move { monitor.run(tx, barrier).await });
+        }
+        drop(tx);
+
+        let outputs = lights
+            .into_iter()
+            .map(|light| Box::new(light) as Box<dyn Output>)
+            .collect();
+        Dispatcher::new(outputs, self.rules).run(rx, barrier).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn rule(light_ids: Vec<&str>) -> Rule {
+        Rule {
+            source: "health::db".to_string(),
+            level: Level::Down,
+            light_ids: light_ids.into_iter().map(String::from).collect(),
+            color: Some((255, 0, 0)),
+            brightness: None,
+        }
+    }
+
+    fn event(source: &str, level: Level) -> Event {
+        Event {
+            source: source.to_string(),
+            level,
+            payload: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn rule_matches_same_source_and_level() {
+        assert!(rule(vec!["kitchen"]).matches(&event("health::db", Level::Down)));
+    }
+
+    #[test]
+    fn rule_does_not_match_different_level() {
+        assert!(!rule(vec!["kitchen"]).matches(&event("health::db", Level::Up)));
+    }
+
+    #[test]
+    fn rule_does_not_match_different_source() {
+        assert!(!rule(vec!["kitchen"]).matches(&event("health::api", Level::Down)));
+    }
+
+    struct RecordingLight {
+        id: String,
+        colors: Arc<Mutex<Vec<(u8, u8, u8)>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl Light for RecordingLight {
+        async fn refresh_state(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn set_on(&mut self, _on: bool) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn set_color(&mut self, red: u8, green: u8, blue: u8) -> anyhow::Result<()> {
+            self.colors.lock().unwrap().push((red, green, blue));
+            Ok(())
+        }
+
+        async fn set_brightness(&mut self, _brightness: u8) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn name(&self) -> String {
+            self.id.clone()
+        }
+
+        fn is_on(&self) -> bool {
+            true
+        }
+
+        fn supports_color(&self) -> bool {
+            true
+        }
+
+        fn red(&self) -> u8 {
+            0
+        }
+
+        fn green(&self) -> u8 {
+            0
+        }
+
+        fn blue(&self) -> u8 {
+            0
+        }
+
+        fn brightness(&self) -> u8 {
+            0
+        }
+    }
+
+    async fn run_dispatcher_once(light_ids: Vec<&str>, event: Event) -> Vec<(u8, u8, u8)> {
+        let colors = Arc::new(Mutex::new(Vec::new()));
+        let light = RecordingLight {
+            id: "kitchen".to_string(),
+            colors: colors.clone(),
+        };
+        let rules = Arc::new(RwLock::new(vec![rule(light_ids)]));
+        let (tx, rx) = mpsc::channel(4);
+        let barrier = Arc::new(Barrier::new(1));
+        let light: Box<dyn Light> = Box::new(light);
+        let dispatcher = Dispatcher::new(vec![Box::new(light) as Box<dyn Output>], rules);
+
+        tx.send(event).await.unwrap();
+        drop(tx);
+        dispatcher.run(rx, barrier).await;
+
+        colors.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn dispatcher_applies_matching_rule_to_named_light() {
+        let colors = run_dispatcher_once(vec!["kitchen"], event("health::db", Level::Down)).await;
+        assert_eq!(colors, vec![(255, 0, 0)]);
+    }
+
+    #[tokio::test]
+    async fn dispatcher_ignores_unknown_light_id() {
+        let colors = run_dispatcher_once(vec!["bedroom"], event("health::db", Level::Down)).await;
+        assert!(colors.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dispatcher_ignores_non_matching_event() {
+        let colors = run_dispatcher_once(vec!["kitchen"], event("health::db", Level::Up)).await;
+        assert!(colors.is_empty());
+    }
+}
@@ -0,0 +1,44 @@
+use crate::{
+    ambient::AmbientConfig,
+    automation::AutomationConfig,
+    integrations::{
+        govee::GoveeConfig, hue::HueConfig, kasa::KasaConfig, openrgb::OpenRgbConfig,
+    },
+    server::ServerConfig,
+};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Default)]
+pub struct CuteLightsConfig {
+    #[serde(default)]
+    pub govee: GoveeConfig,
+    #[serde(default)]
+    pub openrgb: OpenRgbConfig,
+    #[serde(default)]
+    pub hue: HueConfig,
+    #[serde(default)]
+    pub kasa: KasaConfig,
+    #[serde(default)]
+    pub ambient: AmbientConfig,
+    #[serde(default)]
+    pub automation: AutomationConfig,
+    #[serde(default)]
+    pub server: ServerConfig,
+}
+
+impl CuteLightsConfig {
+    pub fn load_default() -> Self {
+        Self::load_from("cute-lights.toml")
+    }
+
+    pub fn load_from(path: &str) -> Self {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| toml::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_to(&self, path: &str) -> anyhow::Result<()> {
+        std::fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use std::time::Duration;
+
+use super::{Effect, Frame};
+
+// ANCHOR - Solid
+
+pub struct Solid {
+    pub rgb: (u8, u8, u8),
+    pub brightness: u8,
+}
+
+impl Effect for Solid {
+    fn render(&mut self, _t: Duration, _light_index: usize, _light_count: usize) -> Frame {
+        Frame {
+            rgb: self.rgb,
+            brightness: self.brightness,
+            on: true,
+        }
+    }
+}
+
+// ANCHOR - ColorWheel
+
+pub struct ColorWheel {
+    pub speed: f32,
+    pub brightness: u8,
+}
+
+impl Effect for ColorWheel {
+    fn render(&mut self, t: Duration, light_index: usize, light_count: usize) -> Frame {
+        let offset = light_index as f32 / light_count.max(1) as f32;
+        let hue = (t.as_secs_f32() * self.speed + offset).rem_euclid(1.0);
+        Frame {
+            rgb: hsv_to_rgb(hue, 1.0, 1.0),
+            brightness: self.brightness,
+            on: true,
+        }
+    }
+}
+
+// ANCHOR - Pulse
+
+pub struct Pulse {
+    pub rgb: (u8, u8, u8),
+    pub speed: f32,
+}
+
+impl Effect for Pulse {
+    fn render(&mut self, t: Duration, _light_index: usize, _light_count: usize) -> Frame {
+        let phase = (t.as_secs_f32() * self.speed * std::f32::consts::TAU).sin();
+        let brightness = (((phase + 1.0) / 2.0) * 255.0).round() as u8;
+        Frame {
+            rgb: self.rgb,
+            brightness,
+            on: true,
+        }
+    }
+}
+
+// ANCHOR - CrossFade
+
+pub struct CrossFade {
+    pub from: (u8, u8, u8),
+    pub to: (u8, u8, u8),
+    pub duration: Duration,
+    pub brightness: u8,
+}
+
+impl Effect for CrossFade {
+    fn render(&mut self, t: Duration, _light_index: usize, _light_count: usize) -> Frame {
+        let progress = (t.as_secs_f32() / self.duration.as_secs_f32()).clamp(0.0, 1.0);
+        let lerp = |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * progress).round() as u8;
+        Frame {
+            rgb: (
+                lerp(self.from.0, self.to.0),
+                lerp(self.from.1, self.to.1),
+                lerp(self.from.2, self.to.2),
+            ),
+            brightness: self.brightness,
+            on: true,
+        }
+    }
+}
+
+/// Standard HSV→RGB conversion, `h`/`s`/`v` all in `0.0..=1.0`.
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (u8, u8, u8) {
+    let i = (h * 6.0).floor();
+    let f = h * 6.0 - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+
+    let (r, g, b) = match i as i32 % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    (
+        (r * 255.0).round() as u8,
+        (g * 255.0).round() as u8,
+        (b * 255.0).round() as u8,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsv_to_rgb_primaries() {
+        assert_eq!(hsv_to_rgb(0.0, 1.0, 1.0), (255, 0, 0));
+        assert_eq!(hsv_to_rgb(1.0 / 3.0, 1.0, 1.0), (0, 255, 0));
+        assert_eq!(hsv_to_rgb(2.0 / 3.0, 1.0, 1.0), (0, 0, 255));
+    }
+
+    #[test]
+    fn hsv_to_rgb_zero_saturation_is_grayscale() {
+        assert_eq!(hsv_to_rgb(0.0, 0.0, 1.0), (255, 255, 255));
+        assert_eq!(hsv_to_rgb(0.5, 0.0, 0.0), (0, 0, 0));
+    }
+}
@@ -0,0 +1,72 @@
+mod built_in;
+
+use std::time::{Duration, Instant};
+
+use crate::{utils::future::FutureBatch, Light};
+
+pub use built_in::{ColorWheel, CrossFade, Pulse, Solid};
+
+// ANCHOR - Effect
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    pub rgb: (u8, u8, u8),
+    pub brightness: u8,
+    pub on: bool,
+}
+
+pub trait Effect: Send {
+    fn render(&mut self, t: Duration, light_index: usize, light_count: usize) -> Frame;
+}
+
+// ANCHOR - Player
+
+pub struct Player {
+    lights: Vec<Box<dyn Light>>,
+    fps: u32,
+    last_sent: Vec<Option<Frame>>,
+}
+
+impl Player {
+    pub fn new(lights: Vec<Box<dyn Light>>, fps: u32) -> Self {
+        let last_sent = vec![None; lights.len()];
+        Self {
+            lights,
+            fps: fps.max(1),
+            last_sent,
+        }
+    }
+
+    pub async fn play(mut self, mut effect: Box<dyn Effect>) -> anyhow::Result<()> {
+        let started = Instant::now();
+        let mut interval = tokio::time::interval(Duration::from_secs_f64(1.0 / self.fps as f64));
+
+        loop {
+            interval.tick().await;
+            let t = started.elapsed();
+            let light_count = self.lights.len();
+
+            let mut batch = FutureBatch::new();
+            for (index, light) in self.lights.drain(..).enumerate() {
+                let frame = effect.render(t, index, light_count);
+                let previous = self.last_sent[index];
+                self.last_sent[index] = Some(frame);
+
+                let mut light = light;
+                batch.push(async move {
+                    if previous.map(|p| p.on) != Some(frame.on) {
+                        let _ = light.set_on(frame.on).await;
+                    }
+                    if previous.map(|p| p.rgb) != Some(frame.rgb) {
+                        let _ = light.set_color(frame.rgb.0, frame.rgb.1, frame.rgb.2).await;
+                    }
+                    if previous.map(|p| p.brightness) != Some(frame.brightness) {
+                        let _ = light.set_brightness(frame.brightness).await;
+                    }
+                    light
+                });
+            }
+            self.lights = batch.run().await;
+        }
+    }
+}
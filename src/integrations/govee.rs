@@ -19,6 +19,7 @@ pub struct GoveeLight {
     red: u8,
     green: u8,
     blue: u8,
+    color_temperature_kelvin: Option<u32>,
     id: String,
 }
 
@@ -33,6 +34,7 @@ impl GoveeLight {
             red: 0,
             green: 0,
             blue: 0,
+            color_temperature_kelvin: None,
             id: ip.to_string(),
         };
 
@@ -57,6 +59,10 @@ impl Light for GoveeLight {
         self.red = response.color.r;
         self.green = response.color.g;
         self.blue = response.color.b;
+        self.color_temperature_kelvin = match response.color_temperature_kelvin {
+            0 => None,
+            kelvin => Some(kelvin),
+        };
 
         Ok(())
     }
@@ -75,11 +81,13 @@ impl Light for GoveeLight {
                 g: green,
                 b: blue,
             },
+            color_temperature_kelvin: None,
         };
         send_message(&self.udp_socket, &self.device_addr, msg, false).await?;
         self.red = red;
         self.green = green;
         self.blue = blue;
+        self.color_temperature_kelvin = None;
         Ok(())
     }
 
@@ -92,6 +100,24 @@ impl Light for GoveeLight {
         Ok(())
     }
 
+    async fn set_color_temperature(&mut self, kelvin: u32) -> anyhow::Result<()> {
+        let msg = Request::Color {
+            color: DeviceColor { r: 0, g: 0, b: 0 },
+            color_temperature_kelvin: Some(kelvin),
+        };
+        send_message(&self.udp_socket, &self.device_addr, msg, false).await?;
+        self.color_temperature_kelvin = Some(kelvin);
+        Ok(())
+    }
+
+    fn color_temperature(&self) -> Option<u32> {
+        self.color_temperature_kelvin
+    }
+
+    fn supports_color_temperature(&self) -> bool {
+        true
+    }
+
     fn id(&self) -> String {
         format!("govee::{}", self.id)
     }
@@ -137,6 +163,54 @@ fn default_scan_timeout() -> u64 {
     5000
 }
 
+/// Broadcasts a LAN scan to the Govee multicast group and collects
+/// `Response::Scan` replies on the bound socket for `scan_timeout` before
+/// returning the aggregated device list. Used by the setup wizard; discovery
+/// connects only to the configured `addresses`.
+pub async fn scan(scan_timeout: std::time::Duration) -> anyhow::Result<Vec<LanDevice>> {
+    let socket = UdpSocket::bind("0.0.0.0:4002").await?;
+    let multicast_addr: SocketAddr = "239.255.255.250:4001".parse()?;
+
+    socket
+        .send_to(
+            serde_json::to_string(&RequestMessage {
+                msg: Request::Scan {
+                    topic: AccountTopic::Reserve,
+                },
+            })?
+            .as_bytes(),
+            multicast_addr,
+        )
+        .await?;
+
+    let mut devices = Vec::new();
+    let deadline = tokio::time::Instant::now() + scan_timeout;
+    let mut buf = [0u8; 1024];
+
+    loop {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        match tokio::time::timeout(remaining, socket.recv_from(&mut buf)).await {
+            Ok(Ok((amt, _))) => {
+                let message: Result<ResponseMessage, _> =
+                    serde_json::from_str(&String::from_utf8_lossy(&buf[..amt]));
+                if let Ok(ResponseMessage {
+                    msg: Response::Scan(device),
+                }) = message
+                {
+                    devices.push(device);
+                }
+            }
+            _ => break,
+        }
+    }
+
+    Ok(devices)
+}
+
 // ANCHOR - GoveeIntegration
 
 pub struct GoveeIntegration;
@@ -214,7 +288,11 @@ pub enum Request {
     #[serde(rename = "brightness")]
     Brightness { value: u8 },
     #[serde(rename = "colorwc")]
-    Color { color: DeviceColor },
+    Color {
+        color: DeviceColor,
+        #[serde(rename = "colorTemInKelvin", skip_serializing_if = "Option::is_none")]
+        color_temperature_kelvin: Option<u32>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -0,0 +1,335 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::{config::CuteLightsConfig, utils::future::FutureBatch};
+
+use super::Light;
+
+// ANCHOR - HueLight
+pub struct HueLight {
+    client: reqwest::Client,
+    bridge_address: String,
+    username: String,
+    light_id: u32,
+    is_on: bool,
+    brightness: u8,
+    red: u8,
+    green: u8,
+    blue: u8,
+    color_temperature_kelvin: Option<u32>,
+}
+
+impl HueLight {
+    fn base_url(&self) -> String {
+        format!(
+            "http://{}/api/{}/lights/{}",
+            self.bridge_address, self.username, self.light_id
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl Light for HueLight {
+    async fn refresh_state(&mut self) -> anyhow::Result<()> {
+        let state: HueLightResponse = self
+            .client
+            .get(self.base_url())
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        self.is_on = state.state.on;
+        self.brightness = state.state.bri;
+        if let Some([r, g, b]) = state.state.xy.map(xy_to_rgb) {
+            self.red = r;
+            self.green = g;
+            self.blue = b;
+        }
+        self.color_temperature_kelvin = state.state.ct.map(|mirek| 1_000_000 / mirek.max(1));
+
+        Ok(())
+    }
+
+    async fn set_on(&mut self, on: bool) -> anyhow::Result<()> {
+        self.client
+            .put(format!("{}/state", self.base_url()))
+            .json(&HashMap::from([("on", serde_json::json!(on))]))
+            .send()
+            .await?;
+        self.is_on = on;
+        Ok(())
+    }
+
+    async fn set_color(&mut self, red: u8, green: u8, blue: u8) -> anyhow::Result<()> {
+        let [x, y] = rgb_to_xy(red, green, blue);
+        self.client
+            .put(format!("{}/state", self.base_url()))
+            .json(&serde_json::json!({ "xy": [x, y] }))
+            .send()
+            .await?;
+        self.red = red;
+        self.green = green;
+        self.blue = blue;
+        self.color_temperature_kelvin = None;
+        Ok(())
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> anyhow::Result<()> {
+        self.client
+            .put(format!("{}/state", self.base_url()))
+            .json(&HashMap::from([("bri", serde_json::json!(brightness))]))
+            .send()
+            .await?;
+        self.brightness = brightness;
+        Ok(())
+    }
+
+    async fn set_color_temperature(&mut self, kelvin: u32) -> anyhow::Result<()> {
+        let mirek = (1_000_000 / kelvin.max(1)).clamp(153, 500);
+        self.client
+            .put(format!("{}/state", self.base_url()))
+            .json(&HashMap::from([("ct", serde_json::json!(mirek))]))
+            .send()
+            .await?;
+        self.color_temperature_kelvin = Some(kelvin);
+        Ok(())
+    }
+
+    fn color_temperature(&self) -> Option<u32> {
+        self.color_temperature_kelvin
+    }
+
+    fn supports_color_temperature(&self) -> bool {
+        true
+    }
+
+    fn id(&self) -> String {
+        format!("hue::{}", self.light_id)
+    }
+
+    fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    fn name(&self) -> String {
+        format!("Hue Light ({})", self.light_id)
+    }
+
+    fn supports_color(&self) -> bool {
+        true
+    }
+
+    fn red(&self) -> u8 {
+        self.red
+    }
+
+    fn green(&self) -> u8 {
+        self.green
+    }
+
+    fn blue(&self) -> u8 {
+        self.blue
+    }
+
+    fn brightness(&self) -> u8 {
+        self.brightness
+    }
+}
+
+/// Converts sRGB to the CIE xy chromaticity pair Hue bulbs expect.
+fn rgb_to_xy(red: u8, green: u8, blue: u8) -> [f32; 2] {
+    let (r, g, b) = (red as f32 / 255.0, green as f32 / 255.0, blue as f32 / 255.0);
+    let gamma = |c: f32| if c > 0.04045 { ((c + 0.055) / 1.055).powf(2.4) } else { c / 12.92 };
+    let (r, g, b) = (gamma(r), gamma(g), gamma(b));
+
+    let x = r * 0.649926 + g * 0.103455 + b * 0.197109;
+    let y = r * 0.234327 + g * 0.743075 + b * 0.022598;
+    let z = r * 0.0000000 + g * 0.053077 + b * 1.035763;
+
+    let sum = x + y + z;
+    if sum == 0.0 {
+        [0.0, 0.0]
+    } else {
+        [x / sum, y / sum]
+    }
+}
+
+/// Approximate inverse of [`rgb_to_xy`], used when refreshing cached state.
+fn xy_to_rgb([x, y]: [f32; 2]) -> [u8; 3] {
+    let z = 1.0 - x - y;
+    let yy = 1.0;
+    let xx = (yy / y) * x;
+    let zz = (yy / y) * z;
+
+    let r = xx * 1.656492 - yy * 0.354851 - zz * 0.255038;
+    let g = -xx * 0.707196 + yy * 1.655397 + zz * 0.036152;
+    let b = xx * 0.051713 - yy * 0.121364 + zz * 1.01153;
+
+    let degamma = |c: f32| {
+        let c = if c <= 0.0031308 {
+            12.92 * c
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        };
+        (c.clamp(0.0, 1.0) * 255.0).round() as u8
+    };
+
+    [degamma(r), degamma(g), degamma(b)]
+}
+
+// ANCHOR - HueConfig
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct HueConfig {
+    pub enabled: bool,
+    pub bridge_address: String,
+    pub username: String,
+    #[serde(default)]
+    pub light_ids: Vec<u32>,
+}
+
+/// Performs Hue's button-press pairing flow: repeatedly asks the bridge for
+/// a username until the user presses its physical link button, used by the
+/// setup wizard.
+pub async fn pair(bridge_address: &str, attempts: u32, retry_delay: std::time::Duration) -> anyhow::Result<String> {
+    let client = reqwest::Client::new();
+
+    for attempt in 0..attempts {
+        let response: Vec<HuePairResponse> = client
+            .post(format!("http://{}/api", bridge_address))
+            .json(&serde_json::json!({ "devicetype": "cute-lights#wizard" }))
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        match response.into_iter().next() {
+            Some(HuePairResponse {
+                success: Some(success),
+                ..
+            }) => return Ok(success.username),
+            Some(HuePairResponse {
+                error: Some(error), ..
+            }) => {
+                eprintln!(
+                    "Press the link button on the Hue bridge ({}/{})... ({})",
+                    attempt + 1,
+                    attempts,
+                    error.description
+                );
+            }
+            _ => {}
+        }
+
+        tokio::time::sleep(retry_delay).await;
+    }
+
+    Err(anyhow::anyhow!("timed out waiting for the Hue bridge link button"))
+}
+
+#[derive(Deserialize, Debug)]
+struct HuePairResponse {
+    success: Option<HuePairSuccess>,
+    error: Option<HuePairError>,
+}
+
+#[derive(Deserialize, Debug)]
+struct HuePairSuccess {
+    username: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct HuePairError {
+    description: String,
+}
+
+// ANCHOR - HueIntegration
+
+pub struct HueIntegration;
+
+#[async_trait::async_trait]
+impl super::Integration for HueIntegration {
+    fn name() -> String {
+        "hue".to_string()
+    }
+
+    async fn discover(config: &'static CuteLightsConfig) -> anyhow::Result<Vec<Box<dyn Light>>> {
+        let client = reqwest::Client::new();
+        let mut batch = FutureBatch::new();
+
+        for &light_id in &config.hue.light_ids {
+            let client = client.clone();
+            batch.push(async move {
+                let mut light = HueLight {
+                    client,
+                    bridge_address: config.hue.bridge_address.clone(),
+                    username: config.hue.username.clone(),
+                    light_id,
+                    is_on: false,
+                    brightness: 0,
+                    red: 0,
+                    green: 0,
+                    blue: 0,
+                    color_temperature_kelvin: None,
+                };
+                match light.refresh_state().await {
+                    Ok(()) => Some(Box::new(light) as Box<dyn Light>),
+                    Err(e) => {
+                        eprintln!("Failed to connect to Hue light {}: {}", light_id, e);
+                        None
+                    }
+                }
+            });
+        }
+
+        Ok(batch.run().await.into_iter().flatten().collect())
+    }
+
+    fn preflight(config: &CuteLightsConfig) -> bool {
+        config.hue.enabled
+    }
+}
+
+// ANCHOR - Messages
+
+#[derive(Deserialize, Debug)]
+struct HueLightResponse {
+    state: HueLightState,
+}
+
+#[derive(Deserialize, Debug)]
+struct HueLightState {
+    on: bool,
+    bri: u8,
+    xy: Option<[f32; 2]>,
+    ct: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rgb_to_xy_matches_known_chromaticities() {
+        let [x, y] = rgb_to_xy(0, 255, 0);
+        assert!((x - 0.115).abs() < 0.01);
+        assert!((y - 0.826).abs() < 0.01);
+
+        // D65 white should land close to the standard white point.
+        let [x, y] = rgb_to_xy(255, 255, 255);
+        assert!((x - 0.3127).abs() < 0.01);
+        assert!((y - 0.3290).abs() < 0.01);
+    }
+
+    #[test]
+    fn rgb_to_xy_black_does_not_divide_by_zero() {
+        assert_eq!(rgb_to_xy(0, 0, 0), [0.0, 0.0]);
+    }
+
+    #[test]
+    fn xy_to_rgb_round_trips_green() {
+        let xy = rgb_to_xy(0, 255, 0);
+        assert_eq!(xy_to_rgb(xy), [0, 255, 0]);
+    }
+}
@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+use crate::{config::CuteLightsConfig, utils::future::FutureBatch};
+
+use super::Light;
+
+/// Upper bound on a device's claimed response length: real Kasa responses
+/// are small JSON blobs, so refuse to allocate for an implausible length
+/// prefix from a misbehaving or spoofed device on the LAN.
+const MAX_RESPONSE_LEN: usize = 64 * 1024;
+
+// ANCHOR - KasaLight
+//
+// Kasa smart plugs speak a simple length-prefixed, autokey-XOR "encrypted"
+// (initial key 171, then each previous ciphertext byte) JSON protocol over
+// TCP port 9999. They don't support color; brightness only
+// applies to dimmer switches and is otherwise ignored by the device.
+pub struct KasaLight {
+    address: String,
+    is_on: bool,
+    brightness: u8,
+}
+
+impl KasaLight {
+    async fn exchange(&self, command: serde_json::Value) -> anyhow::Result<serde_json::Value> {
+        let mut stream = TcpStream::connect((self.address.as_str(), 9999)).await?;
+
+        let payload = encrypt(&command.to_string());
+        stream.write_all(&(payload.len() as u32).to_be_bytes()).await?;
+        stream.write_all(&payload).await?;
+
+        let mut len_buf = [0u8; 4];
+        stream.read_exact(&mut len_buf).await?;
+        let len = u32::from_be_bytes(len_buf) as usize;
+        if len > MAX_RESPONSE_LEN {
+            return Err(anyhow::anyhow!(
+                "Kasa device at {} claimed an implausible response length of {} bytes",
+                self.address,
+                len
+            ));
+        }
+
+        let mut buf = vec![0u8; len];
+        stream.read_exact(&mut buf).await?;
+
+        Ok(serde_json::from_str(&decrypt(&buf))?)
+    }
+}
+
+#[async_trait::async_trait]
+impl Light for KasaLight {
+    async fn refresh_state(&mut self) -> anyhow::Result<()> {
+        let response = self
+            .exchange(serde_json::json!({ "system": { "get_sysinfo": {} } }))
+            .await?;
+        let info = &response["system"]["get_sysinfo"];
+        self.is_on = info["relay_state"].as_u64() == Some(1);
+        if let Some(brightness) = info["brightness"].as_u64() {
+            self.brightness = brightness as u8;
+        }
+        Ok(())
+    }
+
+    async fn set_on(&mut self, on: bool) -> anyhow::Result<()> {
+        self.exchange(serde_json::json!({
+            "system": { "set_relay_state": { "state": on as u8 } }
+        }))
+        .await?;
+        self.is_on = on;
+        Ok(())
+    }
+
+    async fn set_color(&mut self, _red: u8, _green: u8, _blue: u8) -> anyhow::Result<()> {
+        Ok(())
+    }
+
+    async fn set_brightness(&mut self, brightness: u8) -> anyhow::Result<()> {
+        self.exchange(serde_json::json!({
+            "smartlife.iot.dimmer": { "set_brightness": { "brightness": brightness } }
+        }))
+        .await?;
+        self.brightness = brightness;
+        Ok(())
+    }
+
+    fn id(&self) -> String {
+        format!("kasa::{}", self.address)
+    }
+
+    fn is_on(&self) -> bool {
+        self.is_on
+    }
+
+    fn name(&self) -> String {
+        format!("Kasa Device ({})", self.address)
+    }
+
+    fn supports_color(&self) -> bool {
+        false
+    }
+
+    fn red(&self) -> u8 {
+        255
+    }
+
+    fn green(&self) -> u8 {
+        255
+    }
+
+    fn blue(&self) -> u8 {
+        255
+    }
+
+    fn brightness(&self) -> u8 {
+        self.brightness
+    }
+}
+
+fn encrypt(plaintext: &str) -> Vec<u8> {
+    let mut key = 171u8;
+    plaintext
+        .bytes()
+        .map(|b| {
+            let c = b ^ key;
+            key = c;
+            c
+        })
+        .collect()
+}
+
+fn decrypt(ciphertext: &[u8]) -> String {
+    let mut key = 171u8;
+    let bytes: Vec<u8> = ciphertext
+        .iter()
+        .map(|&c| {
+            let b = c ^ key;
+            key = c;
+            b
+        })
+        .collect();
+    String::from_utf8_lossy(&bytes).to_string()
+}
+
+// ANCHOR - KasaConfig
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct KasaConfig {
+    pub enabled: bool,
+    pub addresses: Vec<String>,
+}
+
+// ANCHOR - KasaIntegration
+
+pub struct KasaIntegration;
+
+#[async_trait::async_trait]
+impl super::Integration for KasaIntegration {
+    fn name() -> String {
+        "kasa".to_string()
+    }
+
+    async fn discover(config: &'static CuteLightsConfig) -> anyhow::Result<Vec<Box<dyn Light>>> {
+        let mut batch = FutureBatch::new();
+
+        for address in &config.kasa.addresses {
+            let address = address.clone();
+            batch.push(async move {
+                let mut light = KasaLight {
+                    address: address.clone(),
+                    is_on: false,
+                    brightness: 0,
+                };
+                match light.refresh_state().await {
+                    Ok(()) => Some(Box::new(light) as Box<dyn Light>),
+                    Err(e) => {
+                        eprintln!("Failed to connect to Kasa device at {}: {}", address, e);
+                        None
+                    }
+                }
+            });
+        }
+
+        Ok(batch.run().await.into_iter().flatten().collect())
+    }
+
+    fn preflight(config: &CuteLightsConfig) -> bool {
+        config.kasa.enabled
+    }
+}
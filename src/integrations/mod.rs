@@ -0,0 +1,45 @@
+pub mod govee;
+pub mod hue;
+pub mod kasa;
+pub mod openrgb;
+
+use crate::config::CuteLightsConfig;
+
+#[async_trait::async_trait]
+pub trait Integration {
+    fn name() -> String;
+    async fn discover(config: &'static CuteLightsConfig) -> anyhow::Result<Vec<Box<dyn Light>>>;
+    fn preflight(config: &CuteLightsConfig) -> bool;
+}
+
+#[async_trait::async_trait]
+pub trait Light: Send + Sync {
+    async fn refresh_state(&mut self) -> anyhow::Result<()>;
+    async fn set_on(&mut self, on: bool) -> anyhow::Result<()>;
+    async fn set_color(&mut self, red: u8, green: u8, blue: u8) -> anyhow::Result<()>;
+    async fn set_brightness(&mut self, brightness: u8) -> anyhow::Result<()>;
+
+    fn id(&self) -> String;
+    fn name(&self) -> String;
+    fn is_on(&self) -> bool;
+    fn supports_color(&self) -> bool;
+    fn red(&self) -> u8;
+    fn green(&self) -> u8;
+    fn blue(&self) -> u8;
+    fn brightness(&self) -> u8;
+
+    /// Sets a white color temperature in Kelvin. Optional: the default
+    /// implementation returns an error; integrations that don't support it
+    /// natively can override it to approximate it via [`Light::set_color`].
+    async fn set_color_temperature(&mut self, _kelvin: u32) -> anyhow::Result<()> {
+        Err(anyhow::anyhow!("{} does not support color temperature", self.name()))
+    }
+
+    fn color_temperature(&self) -> Option<u32> {
+        None
+    }
+
+    fn supports_color_temperature(&self) -> bool {
+        false
+    }
+}
@@ -32,6 +32,13 @@ impl Light for OpenRgbLight {
         Ok(())
     }
 
+    /// OpenRGB controllers have no native color-temperature concept, so this
+    /// approximates the requested Kelvin value as plain RGB.
+    async fn set_color_temperature(&mut self, kelvin: u32) -> anyhow::Result<()> {
+        let (r, g, b) = crate::utils::color::kelvin_to_rgb(kelvin);
+        self.set_color(r, g, b).await
+    }
+
     async fn set_brightness(&mut self, _brightness: u8) -> anyhow::Result<()> {
         Ok(())
     }
@@ -86,6 +93,21 @@ impl Default for OpenRgbConfig {
     }
 }
 
+/// Connects to an OpenRGB server and returns the names of its controllers,
+/// used by the setup wizard to confirm a server is reachable before
+/// enabling the integration.
+pub async fn probe(address: &str, port: u16) -> anyhow::Result<Vec<String>> {
+    let address: IpAddr = address.parse()?;
+    let client = OpenRGB::connect_to((address, port)).await?;
+
+    let mut names = Vec::new();
+    for controller_id in 0..client.get_controller_count().await? {
+        names.push(client.get_controller(controller_id).await?.name);
+    }
+
+    Ok(names)
+}
+
 pub struct OpenRgbIntegration;
 
 #[async_trait::async_trait]
@@ -0,0 +1,15 @@
+pub mod ambient;
+pub mod automation;
+pub mod config;
+pub mod discover;
+pub mod effects;
+pub mod integrations;
+pub mod server;
+pub mod utils;
+pub mod wizard;
+
+pub use discover::discover_lights;
+pub use integrations::Light;
+
+/// Convenience alias used by the examples.
+pub type CuteResult<T> = anyhow::Result<T>;
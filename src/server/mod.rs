@@ -0,0 +1,340 @@
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use futures::{SinkExt, StreamExt};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::Mutex,
+};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::Light;
+
+// ANCHOR - ServerConfig
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ServerConfig {
+    pub enabled: bool,
+    pub bind_address: String,
+    pub websocket_bind_address: Option<String>,
+}
+
+// ANCHOR - Protocol
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+enum Command {
+    List,
+    SetColor { id: String, r: u8, g: u8, b: u8 },
+    SetOn { id: String, on: bool },
+    SetBrightness { id: String, brightness: u8 },
+}
+
+#[derive(Debug, Serialize)]
+struct LightSummary {
+    id: String,
+    name: String,
+    on: bool,
+    brightness: u8,
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Reply {
+    Ok { result: serde_json::Value },
+    Error { message: String },
+}
+
+// ANCHOR - Server
+
+type SharedLights = Arc<Mutex<Vec<Box<dyn Light>>>>;
+
+pub struct Server {
+    lights: SharedLights,
+    config: ServerConfig,
+}
+
+impl Server {
+    pub fn new(lights: Vec<Box<dyn Light>>, config: ServerConfig) -> Self {
+        Self {
+            lights: Arc::new(Mutex::new(lights)),
+            config,
+        }
+    }
+
+    pub async fn run(self) -> anyhow::Result<()> {
+        if let Some(ws_address) = self.config.websocket_bind_address.clone() {
+            let lights = self.lights.clone();
+            tokio::spawn(async move {
+                if let Err(e) = run_websocket(ws_address, lights).await {
+                    eprintln!("WebSocket listener stopped: {}", e);
+                }
+            });
+        }
+
+        let tcp_listener = TcpListener::bind(&self.config.bind_address).await?;
+        eprintln!("Listening for control connections on {}", self.config.bind_address);
+
+        loop {
+            let (socket, _) = tcp_listener.accept().await?;
+            let lights = self.lights.clone();
+            tokio::spawn(async move {
+                if let Err(e) = handle_connection(socket, lights).await {
+                    eprintln!("Control connection closed: {}", e);
+                }
+            });
+        }
+    }
+}
+
+async fn run_websocket(bind_address: String, lights: SharedLights) -> anyhow::Result<()> {
+    let listener = TcpListener::bind(&bind_address).await?;
+    eprintln!("Listening for WebSocket control connections on {}", bind_address);
+
+    loop {
+        let (socket, _) = listener.accept().await?;
+        let lights = lights.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_websocket_connection(socket, lights).await {
+                eprintln!("WebSocket connection closed: {}", e);
+            }
+        });
+    }
+}
+
+async fn handle_websocket_connection(socket: TcpStream, lights: SharedLights) -> anyhow::Result<()> {
+    let mut ws = tokio_tungstenite::accept_async(socket).await?;
+
+    while let Some(message) = ws.next().await {
+        let Message::Text(text) = message? else {
+            continue;
+        };
+
+        let reply = match serde_json::from_str::<Command>(&text) {
+            Ok(command) => dispatch(command, &lights).await,
+            Err(e) => Reply::Error {
+                message: format!("invalid command: {}", e),
+            },
+        };
+
+        ws.send(Message::Text(serde_json::to_string(&reply)?)).await?;
+    }
+
+    Ok(())
+}
+
+async fn handle_connection(socket: TcpStream, lights: SharedLights) -> anyhow::Result<()> {
+    let (reader, mut writer) = socket.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let reply = match serde_json::from_str::<Command>(&line) {
+            Ok(command) => dispatch(command, &lights).await,
+            Err(e) => Reply::Error {
+                message: format!("invalid command: {}", e),
+            },
+        };
+
+        let mut payload = serde_json::to_vec(&reply)?;
+        payload.push(b'\n');
+        writer.write_all(&payload).await?;
+    }
+
+    Ok(())
+}
+
+async fn dispatch(command: Command, lights: &SharedLights) -> Reply {
+    let mut lights = lights.lock().await;
+
+    match command {
+        Command::List => {
+            let summaries: Vec<LightSummary> = lights
+                .iter()
+                .map(|light| LightSummary {
+                    id: light.id(),
+                    name: light.name(),
+                    on: light.is_on(),
+                    brightness: light.brightness(),
+                    r: light.red(),
+                    g: light.green(),
+                    b: light.blue(),
+                })
+                .collect();
+            Reply::Ok {
+                result: serde_json::json!(summaries),
+            }
+        }
+        Command::SetColor { id, r, g, b } => {
+            let Some(light) = lights.iter_mut().find(|light| light.id() == id) else {
+                return no_such_light(&id);
+            };
+            to_reply(light.set_color(r, g, b).await)
+        }
+        Command::SetOn { id, on } => {
+            let Some(light) = lights.iter_mut().find(|light| light.id() == id) else {
+                return no_such_light(&id);
+            };
+            to_reply(light.set_on(on).await)
+        }
+        Command::SetBrightness { id, brightness } => {
+            let Some(light) = lights.iter_mut().find(|light| light.id() == id) else {
+                return no_such_light(&id);
+            };
+            to_reply(light.set_brightness(brightness).await)
+        }
+    }
+}
+
+fn no_such_light(id: &str) -> Reply {
+    Reply::Error {
+        message: format!("no light with id {}", id),
+    }
+}
+
+fn to_reply(result: anyhow::Result<()>) -> Reply {
+    match result {
+        Ok(()) => Reply::Ok {
+            result: serde_json::Value::Null,
+        },
+        Err(e) => Reply::Error {
+            message: e.to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeLight {
+        id: String,
+        fail: bool,
+    }
+
+    #[async_trait::async_trait]
+    impl Light for FakeLight {
+        async fn refresh_state(&mut self) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn set_on(&mut self, _on: bool) -> anyhow::Result<()> {
+            if self.fail {
+                anyhow::bail!("simulated failure");
+            }
+            Ok(())
+        }
+
+        async fn set_color(&mut self, _red: u8, _green: u8, _blue: u8) -> anyhow::Result<()> {
+            if self.fail {
+                anyhow::bail!("simulated failure");
+            }
+            Ok(())
+        }
+
+        async fn set_brightness(&mut self, _brightness: u8) -> anyhow::Result<()> {
+            if self.fail {
+                anyhow::bail!("simulated failure");
+            }
+            Ok(())
+        }
+
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn name(&self) -> String {
+            self.id.clone()
+        }
+
+        fn is_on(&self) -> bool {
+            true
+        }
+
+        fn supports_color(&self) -> bool {
+            true
+        }
+
+        fn red(&self) -> u8 {
+            1
+        }
+
+        fn green(&self) -> u8 {
+            2
+        }
+
+        fn blue(&self) -> u8 {
+            3
+        }
+
+        fn brightness(&self) -> u8 {
+            4
+        }
+    }
+
+    fn lights(fail: bool) -> SharedLights {
+        Arc::new(Mutex::new(vec![Box::new(FakeLight {
+            id: "kitchen".to_string(),
+            fail,
+        }) as Box<dyn Light>]))
+    }
+
+    #[tokio::test]
+    async fn dispatch_list_returns_known_lights() {
+        let reply = dispatch(Command::List, &lights(false)).await;
+        let Reply::Ok { result } = reply else {
+            panic!("expected Ok reply");
+        };
+        assert_eq!(result[0]["id"], "kitchen");
+    }
+
+    #[tokio::test]
+    async fn dispatch_set_color_on_unknown_id_errors() {
+        let reply = dispatch(
+            Command::SetColor {
+                id: "missing".to_string(),
+                r: 1,
+                g: 2,
+                b: 3,
+            },
+            &lights(false),
+        )
+        .await;
+        assert!(matches!(reply, Reply::Error { message } if message.contains("missing")));
+    }
+
+    #[tokio::test]
+    async fn dispatch_set_color_on_known_id_succeeds() {
+        let reply = dispatch(
+            Command::SetColor {
+                id: "kitchen".to_string(),
+                r: 1,
+                g: 2,
+                b: 3,
+            },
+            &lights(false),
+        )
+        .await;
+        assert!(matches!(reply, Reply::Ok { .. }));
+    }
+
+    #[tokio::test]
+    async fn dispatch_surfaces_light_errors() {
+        let reply = dispatch(
+            Command::SetOn {
+                id: "kitchen".to_string(),
+                on: true,
+            },
+            &lights(true),
+        )
+        .await;
+        assert!(matches!(reply, Reply::Error { message } if message.contains("simulated failure")));
+    }
+}
@@ -0,0 +1,60 @@
+/// Approximates the sRGB color of a blackbody radiator at `kelvin`, for
+/// integrations that have no native color-temperature control and need to
+/// fall back to plain RGB. Based on Tanner Helland's fit to the Planckian
+/// locus, valid over roughly 1000K-40000K.
+pub fn kelvin_to_rgb(kelvin: u32) -> (u8, u8, u8) {
+    let temp = kelvin.clamp(1000, 40000) as f64 / 100.0;
+
+    let red = if temp <= 66.0 {
+        255.0
+    } else {
+        (329.698727446 * (temp - 60.0).powf(-0.1332047592)).clamp(0.0, 255.0)
+    };
+
+    let green = if temp <= 66.0 {
+        (99.4708025861 * temp.ln() - 161.1195681661).clamp(0.0, 255.0)
+    } else {
+        (288.1221695283 * (temp - 60.0).powf(-0.0755148492)).clamp(0.0, 255.0)
+    };
+
+    let blue = if temp >= 66.0 {
+        255.0
+    } else if temp <= 19.0 {
+        0.0
+    } else {
+        (138.5177312231 * (temp - 10.0).ln() - 305.0447927307).clamp(0.0, 255.0)
+    };
+
+    (red.round() as u8, green.round() as u8, blue.round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kelvin_to_rgb_neutral_white_is_roughly_white() {
+        let (r, g, b) = kelvin_to_rgb(6600);
+        assert_eq!((r, g, b), (255, 255, 255));
+    }
+
+    #[test]
+    fn kelvin_to_rgb_low_end_is_warm() {
+        let (r, g, b) = kelvin_to_rgb(1000);
+        assert_eq!(r, 255);
+        assert!(b < r, "low kelvin should be red/orange, not blue");
+    }
+
+    #[test]
+    fn kelvin_to_rgb_high_end_is_cool() {
+        let (r, g, b) = kelvin_to_rgb(40000);
+        assert_eq!(b, 255);
+        assert!(r < b, "high kelvin should be blue, not red");
+    }
+
+    #[test]
+    fn kelvin_to_rgb_clamps_out_of_range_input() {
+        assert_eq!(kelvin_to_rgb(0), kelvin_to_rgb(1000));
+        assert_eq!(kelvin_to_rgb(1_000_000), kelvin_to_rgb(40000));
+    }
+}
@@ -0,0 +1,31 @@
+use futures::future::BoxFuture;
+
+/// Runs a set of heterogeneous async tasks concurrently and collects their
+/// results once every task has finished. Used to fan out per-device network
+/// calls (discovery, color updates) so a slow or unreachable device doesn't
+/// stall the rest.
+pub struct FutureBatch<T> {
+    futures: Vec<BoxFuture<'static, T>>,
+}
+
+impl<T> FutureBatch<T> {
+    pub fn new() -> Self {
+        Self {
+            futures: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, fut: impl std::future::Future<Output = T> + Send + 'static) {
+        self.futures.push(Box::pin(fut));
+    }
+
+    pub async fn run(self) -> Vec<T> {
+        futures::future::join_all(self.futures).await
+    }
+}
+
+impl<T> Default for FutureBatch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
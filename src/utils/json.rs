@@ -0,0 +1,11 @@
+use serde::{Deserialize, Deserializer};
+
+/// Govee's LAN API encodes booleans as `0`/`1` integers rather than JSON
+/// `true`/`false`; this adapts such fields for use with `#[serde(deserialize_with = "...")]`.
+pub fn boolean_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = u8::deserialize(deserializer)?;
+    Ok(value != 0)
+}
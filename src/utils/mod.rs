@@ -0,0 +1,3 @@
+pub mod color;
+pub mod future;
+pub mod json;
@@ -0,0 +1,175 @@
+use std::{
+    io::Write,
+    time::Duration,
+};
+
+use crate::{
+    config::CuteLightsConfig,
+    integrations::{govee, hue, openrgb, openrgb::OpenRgbConfig},
+};
+
+const CONFIG_PATH: &str = "cute-lights.toml";
+
+pub async fn run() -> anyhow::Result<()> {
+    println!("cute-lights setup wizard\n");
+
+    let config = CuteLightsConfig {
+        govee: wizard_govee().await?,
+        hue: wizard_hue().await?,
+        openrgb: wizard_openrgb().await?,
+        ..CuteLightsConfig::default()
+    };
+
+    config.save_to(CONFIG_PATH)?;
+    println!("\nWrote configuration to {}", CONFIG_PATH);
+
+    Ok(())
+}
+
+async fn wizard_govee() -> anyhow::Result<govee::GoveeConfig> {
+    if !confirm("Scan for Govee LAN devices?")? {
+        return Ok(govee::GoveeConfig::default());
+    }
+
+    let scan_timeout: u64 = prompt("Govee scan timeout in milliseconds")?
+        .parse()
+        .unwrap_or(5000);
+
+    println!("Scanning for Govee devices...");
+    let devices = match govee::scan(Duration::from_millis(scan_timeout)).await {
+        Ok(devices) => devices,
+        Err(e) => {
+            println!("Could not scan for Govee devices: {}", e);
+            return Ok(govee::GoveeConfig::default());
+        }
+    };
+    if devices.is_empty() {
+        println!("No Govee devices found.");
+        return Ok(govee::GoveeConfig::default());
+    }
+
+    let mut addresses = Vec::new();
+    for device in &devices {
+        if confirm(&format!("Include {} ({})?", device.device, device.ip))? {
+            addresses.push(device.ip.to_string());
+        }
+    }
+
+    Ok(govee::GoveeConfig {
+        enabled: !addresses.is_empty(),
+        addresses,
+        scan_timeout,
+    })
+}
+
+async fn wizard_hue() -> anyhow::Result<hue::HueConfig> {
+    if !confirm("Set up a Philips Hue bridge?")? {
+        return Ok(hue::HueConfig::default());
+    }
+
+    let bridge_address = prompt("Hue bridge IP address")?;
+    println!("Press the link button on the bridge now...");
+    let username = match hue::pair(&bridge_address, 30, Duration::from_secs(1)).await {
+        Ok(username) => username,
+        Err(e) => {
+            println!("Could not pair with Hue bridge: {}", e);
+            return Ok(hue::HueConfig::default());
+        }
+    };
+
+    let light_ids = parse_light_ids(&prompt("Comma-separated Hue light IDs to control (e.g. 1,2,3)")?);
+
+    Ok(hue::HueConfig {
+        enabled: !light_ids.is_empty(),
+        bridge_address,
+        username,
+        light_ids,
+    })
+}
+
+async fn wizard_openrgb() -> anyhow::Result<OpenRgbConfig> {
+    if !confirm("Connect to an OpenRGB server?")? {
+        return Ok(OpenRgbConfig::default());
+    }
+
+    let address = prompt("OpenRGB server address")?;
+    let port: u16 = prompt("OpenRGB server port")?.parse().unwrap_or(6742);
+
+    match openrgb::probe(&address, port).await {
+        Ok(controllers) => {
+            println!(
+                "Found {} controller(s): {}",
+                controllers.len(),
+                controllers.join(", ")
+            );
+            Ok(OpenRgbConfig {
+                enabled: true,
+                address,
+                port,
+            })
+        }
+        Err(e) => {
+            println!("Could not connect to OpenRGB server: {}", e);
+            Ok(OpenRgbConfig::default())
+        }
+    }
+}
+
+fn prompt(message: &str) -> anyhow::Result<String> {
+    print!("{}: ", message);
+    std::io::stdout().flush()?;
+
+    let mut input = String::new();
+    std::io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+fn confirm(message: &str) -> anyhow::Result<bool> {
+    let answer = prompt(&format!("{} [y/N]", message))?;
+    Ok(parse_confirm(&answer))
+}
+
+fn parse_confirm(answer: &str) -> bool {
+    matches!(answer.to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Parses a comma-separated list of Hue light IDs, silently dropping entries
+/// that aren't valid numeric IDs rather than failing the whole wizard step.
+fn parse_light_ids(input: &str) -> Vec<u32> {
+    input
+        .split(',')
+        .filter_map(|id| id.trim().parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_confirm_accepts_y_and_yes_case_insensitively() {
+        assert!(parse_confirm("y"));
+        assert!(parse_confirm("Y"));
+        assert!(parse_confirm("yes"));
+        assert!(parse_confirm("YES"));
+    }
+
+    #[test]
+    fn parse_confirm_rejects_anything_else() {
+        assert!(!parse_confirm(""));
+        assert!(!parse_confirm("n"));
+        assert!(!parse_confirm("sure"));
+    }
+
+    #[test]
+    fn parse_light_ids_collects_valid_numeric_ids() {
+        assert_eq!(parse_light_ids("1,2,3"), vec![1, 2, 3]);
+        assert_eq!(parse_light_ids(" 1 , 2 "), vec![1, 2]);
+    }
+
+    #[test]
+    fn parse_light_ids_drops_invalid_entries() {
+        assert_eq!(parse_light_ids("1,abc,3"), vec![1, 3]);
+        assert_eq!(parse_light_ids(""), Vec::<u32>::new());
+    }
+}